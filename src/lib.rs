@@ -0,0 +1,782 @@
+//! This library implements string similarity metrics.
+
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+
+pub mod process;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StrSimError {
+    DifferentLengthArgs,
+}
+
+impl fmt::Display for StrSimError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            StrSimError::DifferentLengthArgs => "Differing length arguments provided",
+        };
+
+        write!(fmt, "{}", text)
+    }
+}
+
+impl Error for StrSimError {
+    fn description(&self) -> &str {
+        "error"
+    }
+}
+
+pub type HammingResult<T> = Result<T, StrSimError>;
+
+/// Calculates the number of positions in the two sequences where the elements
+/// differ. Returns an error if the sequences have different lengths.
+pub fn generic_hamming<Iter1, Iter2, Elem1, Elem2>(a: Iter1, b: Iter2) -> HammingResult<usize>
+where
+    Iter1: IntoIterator<Item = Elem1>,
+    Iter2: IntoIterator<Item = Elem2>,
+    Elem1: PartialEq<Elem2>,
+{
+    let mut a_iter = a.into_iter();
+    let mut b_iter = b.into_iter();
+    let mut distance = 0;
+
+    loop {
+        match (a_iter.next(), b_iter.next()) {
+            (Some(x), Some(y)) => {
+                if x != y {
+                    distance += 1;
+                }
+            }
+            (None, None) => return Ok(distance),
+            _ => return Err(StrSimError::DifferentLengthArgs),
+        }
+    }
+}
+
+/// Calculates the Hamming distance between two strings.
+///
+/// ```
+/// use strsim::hamming;
+///
+/// assert_eq!(Ok(3), hamming("hamming", "hammers"));
+/// ```
+pub fn hamming(a: &str, b: &str) -> HammingResult<usize> {
+    generic_hamming(a.chars(), b.chars())
+}
+
+/// Calculates the Levenshtein distance between two sequences of elements.
+pub fn generic_levenshtein<T>(a: &[T], b: &[T]) -> usize
+where
+    T: PartialEq,
+{
+    let a_len = a.len();
+    let b_len = b.len();
+
+    if a_len == 0 {
+        return b_len;
+    }
+    if b_len == 0 {
+        return a_len;
+    }
+
+    let mut prev_row: Vec<usize> = (0..=b_len).collect();
+    let mut curr_row = vec![0usize; b_len + 1];
+
+    for i in 1..=a_len {
+        curr_row[0] = i;
+        for j in 1..=b_len {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr_row[j] = min(
+                min(prev_row[j] + 1, curr_row[j - 1] + 1),
+                prev_row[j - 1] + cost,
+            );
+        }
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    prev_row[b_len]
+}
+
+/// Computes a single word (`b.len() <= 64`) of Myers' (1999) bit-parallel
+/// Levenshtein distance. Each distinct element of `b` gets a 64-bit
+/// equality bitmask (`Peq`); the horizontal/vertical delta vectors for the
+/// whole row are then advanced with a handful of word-sized bit operations
+/// instead of the `O(b.len())` inner loop the classic DP needs per
+/// character of `a`, bringing the total cost down to `O(a.len())` word
+/// operations.
+fn myers_levenshtein<T>(a: &[T], b: &[T]) -> usize
+where
+    T: Eq + Hash,
+{
+    let m = b.len();
+    if m == 0 {
+        return a.len();
+    }
+    if a.is_empty() {
+        return m;
+    }
+
+    let mut peq: HashMap<&T, u64> = HashMap::new();
+    for (j, elem) in b.iter().enumerate() {
+        *peq.entry(elem).or_insert(0) |= 1u64 << j;
+    }
+
+    let last_bit = 1u64 << (m - 1);
+    let mut vp: u64 = !0;
+    let mut vn: u64 = 0;
+    let mut score = m;
+
+    for elem in a {
+        let eq = peq.get(elem).copied().unwrap_or(0);
+        let x = eq | vn;
+        let d0 = (((x & vp).wrapping_add(vp)) ^ vp) | x;
+        let hp = vn | !(d0 | vp);
+        let hn = d0 & vp;
+
+        if hp & last_bit != 0 {
+            score += 1;
+        }
+        if hn & last_bit != 0 {
+            score -= 1;
+        }
+
+        let hp = (hp << 1) | 1;
+        let hn = hn << 1;
+        vp = hn | !(d0 | hp);
+        vn = d0 & hp;
+    }
+
+    score
+}
+
+/// Calculates the Levenshtein distance between two sequences of elements,
+/// dispatching to the bit-parallel Myers algorithm when `b.len() <= 64`
+/// (a single machine word holds its equality masks) and falling back to
+/// the classic DP for longer patterns. Results are identical to
+/// [`generic_levenshtein`] either way.
+pub fn generic_levenshtein_fast<T>(a: &[T], b: &[T]) -> usize
+where
+    T: Eq + Hash,
+{
+    if b.len() <= 64 {
+        myers_levenshtein(a, b)
+    } else {
+        generic_levenshtein(a, b)
+    }
+}
+
+/// Calculates the Levenshtein distance between two strings.
+///
+/// ```
+/// use strsim::levenshtein;
+///
+/// assert_eq!(3, levenshtein("kitten", "sitting"));
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    generic_levenshtein_fast(&a_chars, &b_chars)
+}
+
+/// Calculates the Levenshtein distance between two sequences of elements,
+/// bailing out early once the distance is known to exceed `max`.
+///
+/// This uses Ukkonen's banded DP: a cell `(i, j)` can only hold a value
+/// `<= max` when `|i - j| <= max`, so each row only visits the diagonal
+/// band `[i - max, i + max]` instead of the whole row, which turns the
+/// O(a.len() * b.len()) algorithm into O(a.len() * max). If the smallest
+/// value in a row already exceeds `max`, no later row can hold a smaller
+/// value, so the distance is reported as `max + 1` without visiting the
+/// remaining rows.
+pub fn generic_levenshtein_limit<T>(a: &[T], b: &[T], max: usize) -> usize
+where
+    T: PartialEq,
+{
+    let a_len = a.len();
+    let b_len = b.len();
+
+    if a_len.abs_diff(b_len) > max {
+        return max + 1;
+    }
+    if a_len == 0 {
+        return b_len;
+    }
+    if b_len == 0 {
+        return a_len;
+    }
+
+    // Only the diagonal band `|i - j| <= max` can ever hold a value `<=
+    // max`, so each row is kept as a fixed-width window of `2 * max + 1`
+    // cells indexed by the diagonal offset `d = j - i`, instead of a full
+    // `b_len + 1` row. That bounds both the per-row work and the reset
+    // between rows to `O(max)`, giving `O(a_len * max)` total rather than
+    // `O(a_len * b_len)`.
+    let width = 2 * max + 1;
+    let sentinel = max + 1;
+    let offset = max as isize;
+
+    let mut prev = vec![sentinel; width];
+    let mut curr = vec![sentinel; width];
+    for j in 0..=min(max, b_len) {
+        prev[(j as isize + offset) as usize] = j;
+    }
+
+    for i in 1..=a_len {
+        let lo = i.saturating_sub(max).max(1);
+        let hi = min(i + max, b_len);
+        if lo > hi {
+            return sentinel;
+        }
+
+        for slot in curr.iter_mut() {
+            *slot = sentinel;
+        }
+
+        // Column 0 means "delete the whole prefix of `a` seen so far"; it
+        // is only inside the band while `i <= max`.
+        let mut row_min = sentinel;
+        if i <= max {
+            let idx0 = (offset - i as isize) as usize;
+            curr[idx0] = i;
+            row_min = i;
+        }
+
+        for j in lo..=hi {
+            let idx = (j as isize - i as isize + offset) as usize;
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            // `prev`/`curr` already hold the `sentinel` for any diagonal
+            // outside their row's band, so each move can be taken
+            // unconditionally and the sentinel propagates on its own.
+            let deletion = if idx + 1 < width {
+                prev[idx + 1].saturating_add(1)
+            } else {
+                sentinel
+            };
+            let insertion = if idx > 0 {
+                curr[idx - 1].saturating_add(1)
+            } else {
+                sentinel
+            };
+            let substitution = prev[idx].saturating_add(cost);
+            let value = min(min(deletion, insertion), substitution);
+            curr[idx] = value;
+            row_min = min(row_min, value);
+        }
+
+        if row_min > max {
+            return sentinel;
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let final_idx = (b_len as isize - a_len as isize + offset) as usize;
+    min(prev[final_idx], sentinel)
+}
+
+/// Calculates the Levenshtein distance between two strings, bailing out
+/// early once the distance is known to exceed `max`. Returns `max + 1`
+/// when the true distance is greater than `max`, otherwise the exact
+/// distance.
+///
+/// ```
+/// use strsim::levenshtein_limit;
+///
+/// assert_eq!(3, levenshtein_limit("kitten", "sitting", 5));
+/// assert_eq!(2, levenshtein_limit("kitten", "sitting", 1));
+/// ```
+pub fn levenshtein_limit(a: &str, b: &str, max: usize) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    generic_levenshtein_limit(&a_chars, &b_chars, max)
+}
+
+/// Calculates a normalized score of the Levenshtein algorithm between 0.0 and
+/// 1.0 (inclusive), where 1.0 means the strings are the same.
+pub fn normalized_levenshtein(a: &str, b: &str) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+
+    let longest = max(a.chars().count(), b.chars().count());
+    1.0 - (levenshtein(a, b) as f64) / (longest as f64)
+}
+
+/// Calculates the Optimal String Alignment distance between two strings. The
+/// difference between this and the Damerau-Levenshtein distance is that this
+/// implementation does not allow a substring to be edited more than once.
+pub fn osa_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let a_len = a_chars.len();
+    let b_len = b_chars.len();
+
+    if a_len == 0 {
+        return b_len;
+    }
+    if b_len == 0 {
+        return a_len;
+    }
+
+    let mut prev_two_rows = vec![0usize; b_len + 1];
+    let mut prev_row: Vec<usize> = (0..=b_len).collect();
+    let mut curr_row = vec![0usize; b_len + 1];
+
+    for i in 1..=a_len {
+        curr_row[0] = i;
+        for j in 1..=b_len {
+            let cost = if a_chars[i - 1] == b_chars[j - 1] { 0 } else { 1 };
+            let mut distance = min(
+                min(prev_row[j] + 1, curr_row[j - 1] + 1),
+                prev_row[j - 1] + cost,
+            );
+            if i > 1
+                && j > 1
+                && a_chars[i - 1] == b_chars[j - 2]
+                && a_chars[i - 2] == b_chars[j - 1]
+            {
+                distance = min(distance, prev_two_rows[j - 2] + 1);
+            }
+            curr_row[j] = distance;
+        }
+        std::mem::swap(&mut prev_two_rows, &mut prev_row);
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    prev_row[b_len]
+}
+
+/// Calculates the Damerau-Levenshtein distance between two sequences of
+/// elements, allowing adjacent transpositions in addition to insertions,
+/// deletions and substitutions.
+pub fn generic_damerau_levenshtein<T>(a: &[T], b: &[T]) -> usize
+where
+    T: Eq + Hash + Clone,
+{
+    let a_len = a.len();
+    let b_len = b.len();
+
+    if a_len == 0 {
+        return b_len;
+    }
+    if b_len == 0 {
+        return a_len;
+    }
+
+    let max_dist = a_len + b_len;
+    let mut last_row: HashMap<T, usize> = HashMap::new();
+    let mut d = vec![vec![0usize; b_len + 2]; a_len + 2];
+
+    d[0][0] = max_dist;
+    for i in 0..=a_len {
+        d[i + 1][0] = max_dist;
+        d[i + 1][1] = i;
+    }
+    for j in 0..=b_len {
+        d[0][j + 1] = max_dist;
+        d[1][j + 1] = j;
+    }
+
+    for i in 1..=a_len {
+        let mut last_match_col = 0;
+        for j in 1..=b_len {
+            let last_match_row = *last_row.get(&b[j - 1]).unwrap_or(&0);
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            d[i + 1][j + 1] = min(
+                min(d[i][j] + cost, d[i + 1][j] + 1),
+                min(
+                    d[i][j + 1] + 1,
+                    d[last_match_row][last_match_col]
+                        + (i - last_match_row - 1)
+                        + 1
+                        + (j - last_match_col - 1),
+                ),
+            );
+
+            if cost == 0 {
+                last_match_col = j;
+            }
+        }
+        last_row.insert(a[i - 1].clone(), i);
+    }
+
+    d[a_len + 1][b_len + 1]
+}
+
+/// Calculates the Damerau-Levenshtein distance between two strings.
+///
+/// ```
+/// use strsim::damerau_levenshtein;
+///
+/// assert_eq!(1, damerau_levenshtein("ab", "ba"));
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    generic_damerau_levenshtein(&a_chars, &b_chars)
+}
+
+/// Calculates a normalized score of the Damerau-Levenshtein algorithm between
+/// 0.0 and 1.0 (inclusive), where 1.0 means the strings are the same.
+pub fn normalized_damerau_levenshtein(a: &str, b: &str) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+
+    let longest = max(a.chars().count(), b.chars().count());
+    1.0 - (damerau_levenshtein(a, b) as f64) / (longest as f64)
+}
+
+/// Calculates the Jaro similarity between two sequences of elements.
+/// Returns a value between 0.0 and 1.0 (inclusive), where 1.0 means the
+/// sequences are the same.
+pub fn generic_jaro<T>(a: &[T], b: &[T]) -> f64
+where
+    T: PartialEq,
+{
+    let a_len = a.len();
+    let b_len = b.len();
+
+    if a_len == 0 && b_len == 0 {
+        return 1.0;
+    } else if a_len == 0 || b_len == 0 {
+        return 0.0;
+    }
+
+    let search_range = (max(a_len, b_len) / 2).saturating_sub(1);
+
+    let mut a_matched = vec![false; a_len];
+    let mut b_matched = vec![false; b_len];
+    let mut matches = 0usize;
+
+    for i in 0..a_len {
+        let lo = i.saturating_sub(search_range);
+        let hi = min(i + search_range + 1, b_len);
+
+        for j in lo..hi {
+            if !b_matched[j] && a[i] == b[j] {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0usize;
+    let mut b_index = 0;
+    for i in 0..a_len {
+        if a_matched[i] {
+            while !b_matched[b_index] {
+                b_index += 1;
+            }
+            if a[i] != b[b_index] {
+                transpositions += 1;
+            }
+            b_index += 1;
+        }
+    }
+    let transpositions = transpositions / 2;
+
+    let matches = matches as f64;
+    (matches / a_len as f64 + matches / b_len as f64 + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+/// Calculates the Jaro similarity between two strings. The returned value
+/// is between 0.0 and 1.0 (inclusive), where 1.0 means the strings are the
+/// same.
+///
+/// ```
+/// use strsim::jaro;
+///
+/// assert!((0.392 - jaro("Friedrich Nietzsche", "Jean-Paul Sartre")).abs() < 0.001);
+/// ```
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    generic_jaro(&a_chars, &b_chars)
+}
+
+/// Tuning parameters for [`jaro_winkler_config`] and
+/// [`generic_jaro_winkler_config`]: how strongly a shared prefix boosts the
+/// plain Jaro score, the Jaro score above which the boost kicks in at all,
+/// and how many leading elements count toward that prefix. The `Default`
+/// impl reproduces the fixed behavior of [`jaro_winkler`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JaroWinklerConfig {
+    pub prefix_weight: f64,
+    pub boost_threshold: f64,
+    pub max_prefix_len: usize,
+}
+
+impl Default for JaroWinklerConfig {
+    fn default() -> Self {
+        JaroWinklerConfig {
+            prefix_weight: 0.1,
+            boost_threshold: 0.7,
+            max_prefix_len: 4,
+        }
+    }
+}
+
+/// Calculates the Jaro-Winkler similarity between two strings. The returned
+/// value is between 0.0 and 1.0 (inclusive), where 1.0 means the strings are
+/// the same. This metric gives more favorable ratings to strings that share
+/// a common prefix.
+///
+/// ```
+/// use strsim::jaro_winkler;
+///
+/// assert!((0.985 - jaro_winkler("cheese burger", "cheeseburger")).abs() < 0.001);
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    jaro_winkler_config(a, b, &JaroWinklerConfig::default())
+}
+
+/// Calculates the Jaro-Winkler similarity between two strings using a
+/// custom [`JaroWinklerConfig`], e.g. to raise the prefix cap beyond 4 for
+/// long structured identifiers or to change when the boost kicks in.
+pub fn jaro_winkler_config(a: &str, b: &str, config: &JaroWinklerConfig) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    generic_jaro_winkler_config(&a_chars, &b_chars, config)
+}
+
+/// Calculates the Jaro-Winkler similarity between two sequences of
+/// elements, boosting the plain [`generic_jaro`] score for sequences that
+/// share a prefix of up to 4 elements.
+pub fn generic_jaro_winkler<T>(a: &[T], b: &[T]) -> f64
+where
+    T: PartialEq,
+{
+    generic_jaro_winkler_config(a, b, &JaroWinklerConfig::default())
+}
+
+/// Calculates the Jaro-Winkler similarity between two sequences of elements
+/// using a custom [`JaroWinklerConfig`]. If `prefix_weight * max_prefix_len`
+/// would push the result outside `[0, 1]`, the boost is skipped and the
+/// plain Jaro score is returned instead.
+pub fn generic_jaro_winkler_config<T>(a: &[T], b: &[T], config: &JaroWinklerConfig) -> f64
+where
+    T: PartialEq,
+{
+    let jaro_distance = generic_jaro(a, b);
+
+    if config.prefix_weight * config.max_prefix_len as f64 > 1.0 {
+        return jaro_distance;
+    }
+    if jaro_distance <= config.boost_threshold {
+        return jaro_distance;
+    }
+
+    let prefix_length = a
+        .iter()
+        .zip(b.iter())
+        .take(config.max_prefix_len)
+        .take_while(|(a_elem, b_elem)| a_elem == b_elem)
+        .count();
+
+    jaro_distance + config.prefix_weight * prefix_length as f64 * (1.0 - jaro_distance)
+}
+
+fn generic_bigrams<T>(s: &[T]) -> Vec<(T, T)>
+where
+    T: Clone,
+{
+    s.windows(2).map(|pair| (pair[0].clone(), pair[1].clone())).collect()
+}
+
+pub(crate) fn bigrams(s: &str) -> Vec<(char, char)> {
+    let chars: Vec<char> = s.chars().collect();
+    generic_bigrams(&chars)
+}
+
+/// Calculates the Sørensen-Dice coefficient between two sequences of
+/// elements, comparing the bigrams (pairs of adjacent elements) that make
+/// them up. Returns a value between 0.0 and 1.0 (inclusive), where 1.0
+/// means the sequences are the same.
+pub fn generic_sorensen_dice<T>(a: &[T], b: &[T]) -> f64
+where
+    T: Eq + Hash + Clone,
+{
+    let a_bigrams = generic_bigrams(a);
+    let b_bigrams = generic_bigrams(b);
+
+    if a_bigrams.is_empty() && b_bigrams.is_empty() {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+
+    let mut b_counts: HashMap<(T, T), i32> = HashMap::new();
+    for bigram in &b_bigrams {
+        *b_counts.entry(bigram.clone()).or_insert(0) += 1;
+    }
+
+    let mut intersection = 0usize;
+    for bigram in &a_bigrams {
+        if let Some(count) = b_counts.get_mut(bigram) {
+            if *count > 0 {
+                *count -= 1;
+                intersection += 1;
+            }
+        }
+    }
+
+    (2 * intersection) as f64 / (a_bigrams.len() + b_bigrams.len()) as f64
+}
+
+/// Calculates the Sørensen-Dice coefficient between two strings, comparing
+/// the bigrams (pairs of adjacent characters) that make them up. Returns a
+/// value between 0.0 and 1.0 (inclusive), where 1.0 means the strings are
+/// the same.
+///
+/// ```
+/// use strsim::sorensen_dice;
+///
+/// assert_eq!(1.0, sorensen_dice("", ""));
+/// ```
+pub fn sorensen_dice(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    generic_sorensen_dice(&a_chars, &b_chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hamming_works() {
+        assert_eq!(Ok(3), hamming("hamming", "hammers"));
+        assert_eq!(Err(StrSimError::DifferentLengthArgs), hamming("hamming", "ham"));
+    }
+
+    #[test]
+    fn levenshtein_works() {
+        assert_eq!(0, levenshtein("kitten", "kitten"));
+        assert_eq!(3, levenshtein("kitten", "sitting"));
+        assert_eq!(7, levenshtein("", "sitting"));
+    }
+
+    #[test]
+    fn myers_levenshtein_matches_classic_dp() {
+        let long_a: Vec<char> = "abcde".repeat(40).chars().collect();
+        let long_b: Vec<char> = "abfde".repeat(40).chars().collect();
+        assert_eq!(
+            generic_levenshtein(&long_a, &long_b),
+            generic_levenshtein_fast(&long_a, &long_b)
+        );
+
+        let pattern: Vec<char> = "a".repeat(64).chars().collect();
+        let query: Vec<char> = "a".repeat(30).chars().chain("b".repeat(34).chars()).collect();
+        assert_eq!(
+            generic_levenshtein(&query, &pattern),
+            generic_levenshtein_fast(&query, &pattern)
+        );
+    }
+
+    #[test]
+    fn levenshtein_limit_works() {
+        assert_eq!(3, levenshtein_limit("kitten", "sitting", 10));
+        assert_eq!(2, levenshtein_limit("kitten", "sitting", 1));
+        assert_eq!(0, levenshtein_limit("same", "same", 0));
+    }
+
+    #[test]
+    fn normalized_levenshtein_works() {
+        assert!((1.0 - normalized_levenshtein("kitten", "kitten")).abs() < f64::EPSILON);
+        assert_eq!(1.0, normalized_levenshtein("", ""));
+    }
+
+    #[test]
+    fn osa_distance_works() {
+        assert_eq!(1, osa_distance("ab", "ba"));
+        assert_eq!(3, osa_distance("ca", "abc"));
+    }
+
+    #[test]
+    fn damerau_levenshtein_works() {
+        assert_eq!(1, damerau_levenshtein("ab", "ba"));
+        assert_eq!(2, damerau_levenshtein("ca", "abc"));
+    }
+
+    #[test]
+    fn jaro_works() {
+        assert_eq!(1.0, jaro("", ""));
+        assert!((0.392 - jaro("Friedrich Nietzsche", "Jean-Paul Sartre")).abs() < 0.001);
+    }
+
+    #[test]
+    fn jaro_winkler_works() {
+        assert!((0.985 - jaro_winkler("cheese burger", "cheeseburger")).abs() < 0.001);
+    }
+
+    #[test]
+    fn sorensen_dice_works() {
+        assert_eq!(1.0, sorensen_dice("", ""));
+        assert!(sorensen_dice("night", "nacht") > 0.0);
+    }
+
+    #[test]
+    fn generic_jaro_matches_str_entry_point() {
+        let a: Vec<u32> = "night".chars().map(|c| c as u32).collect();
+        let b: Vec<u32> = "nacht".chars().map(|c| c as u32).collect();
+        assert_eq!(jaro("night", "nacht"), generic_jaro(&a, &b));
+    }
+
+    #[test]
+    fn generic_jaro_winkler_boosts_shared_prefix() {
+        let a = ["the", "quick", "brown", "fox"];
+        let b = ["the", "quick", "brown", "dog"];
+        assert!(generic_jaro_winkler(&a, &b) > generic_jaro(&a, &b));
+    }
+
+    #[test]
+    fn jaro_winkler_config_default_matches_jaro_winkler() {
+        let config = JaroWinklerConfig::default();
+        assert_eq!(
+            jaro_winkler("cheese burger", "cheeseburger"),
+            jaro_winkler_config("cheese burger", "cheeseburger", &config)
+        );
+    }
+
+    #[test]
+    fn jaro_winkler_config_allows_longer_prefix() {
+        let config = JaroWinklerConfig {
+            prefix_weight: 0.1,
+            boost_threshold: 0.7,
+            max_prefix_len: 8,
+        };
+        let longer_prefix = jaro_winkler_config("ID-2024-0001", "ID-2024-0002", &config);
+        let default_prefix = jaro_winkler("ID-2024-0001", "ID-2024-0002");
+        assert!(longer_prefix >= default_prefix);
+    }
+
+    #[test]
+    fn jaro_winkler_config_falls_back_to_jaro_when_overflowing() {
+        let config = JaroWinklerConfig {
+            prefix_weight: 0.5,
+            boost_threshold: 0.7,
+            max_prefix_len: 4,
+        };
+        assert_eq!(
+            jaro("martha", "marhta"),
+            jaro_winkler_config("martha", "marhta", &config)
+        );
+    }
+
+    #[test]
+    fn generic_sorensen_dice_matches_str_entry_point() {
+        let a: Vec<u32> = "night".chars().map(|c| c as u32).collect();
+        let b: Vec<u32> = "nacht".chars().map(|c| c as u32).collect();
+        assert_eq!(sorensen_dice("night", "nacht"), generic_sorensen_dice(&a, &b));
+    }
+}
@@ -0,0 +1,251 @@
+//! Matching a single query against many candidate strings.
+//!
+//! The top-level functions (e.g. [`jaro`](crate::jaro)) recompute any
+//! per-string state they need on every call, which is wasteful when the
+//! same query is scored against a large list of choices. [`extract`] and
+//! [`extract_one`] cover the common "fuzzy search a list" case, and the
+//! `*Query` handles let callers preprocess the query once and reuse it
+//! across many candidates.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::{bigrams, generic_jaro, jaro, jaro_winkler, normalized_levenshtein, sorensen_dice};
+
+/// Selects which similarity metric [`extract`] and [`extract_one`] score
+/// candidates with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scorer {
+    Jaro,
+    JaroWinkler,
+    NormalizedLevenshtein,
+    SorensenDice,
+}
+
+impl Scorer {
+    fn score(self, query: &str, choice: &str) -> f64 {
+        match self {
+            Scorer::Jaro => jaro(query, choice),
+            Scorer::JaroWinkler => jaro_winkler(query, choice),
+            Scorer::NormalizedLevenshtein => normalized_levenshtein(query, choice),
+            Scorer::SorensenDice => sorensen_dice(query, choice),
+        }
+    }
+}
+
+/// A single scored candidate returned by [`extract`] and [`extract_one`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match<'a> {
+    pub choice: &'a str,
+    pub score: f64,
+    pub index: usize,
+}
+
+/// A query preprocessed once per [`extract`]/[`extract_one`] call and then
+/// reused for every candidate, instead of rebuilding per-string state (char
+/// vectors, bigram multisets) on each comparison. Scorers without a
+/// dedicated `*Query` handle fall back to the plain top-level function.
+enum PreparedQuery<'q> {
+    Jaro(JaroQuery),
+    SorensenDice(SorensenDiceQuery),
+    Uncached(&'q str, Scorer),
+}
+
+impl PreparedQuery<'_> {
+    fn new(query: &str, scorer: Scorer) -> PreparedQuery<'_> {
+        match scorer {
+            Scorer::Jaro => PreparedQuery::Jaro(JaroQuery::new(query)),
+            Scorer::SorensenDice => PreparedQuery::SorensenDice(SorensenDiceQuery::new(query)),
+            other => PreparedQuery::Uncached(query, other),
+        }
+    }
+
+    fn score(&self, choice: &str) -> f64 {
+        match self {
+            PreparedQuery::Jaro(query) => query.score(choice),
+            PreparedQuery::SorensenDice(query) => query.score(choice),
+            PreparedQuery::Uncached(query, scorer) => scorer.score(query, choice),
+        }
+    }
+}
+
+/// Scores every choice in `choices` against `query` using `scorer`, drops
+/// anything below `score_cutoff` (if given), sorts the rest best-first and
+/// truncates to `limit` results.
+///
+/// `query` is preprocessed once (via [`JaroQuery`]/[`SorensenDiceQuery`]
+/// where a cached handle exists for `scorer`) and reused for every
+/// candidate, rather than recomputed on each comparison.
+pub fn extract<'a>(
+    query: &str,
+    choices: &'a [&str],
+    scorer: Scorer,
+    limit: usize,
+    score_cutoff: Option<f64>,
+) -> Vec<Match<'a>> {
+    let prepared = PreparedQuery::new(query, scorer);
+
+    let mut matches: Vec<Match<'a>> = choices
+        .iter()
+        .enumerate()
+        .map(|(index, &choice)| Match {
+            choice,
+            score: prepared.score(choice),
+            index,
+        })
+        .filter(|candidate| match score_cutoff {
+            Some(cutoff) => candidate.score >= cutoff,
+            None => true,
+        })
+        .collect();
+
+    matches.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+    matches.truncate(limit);
+    matches
+}
+
+/// Returns the single best-scoring choice, or `None` if `choices` is empty
+/// or every candidate falls below `score_cutoff`.
+pub fn extract_one<'a>(
+    query: &str,
+    choices: &'a [&str],
+    scorer: Scorer,
+    score_cutoff: Option<f64>,
+) -> Option<Match<'a>> {
+    extract(query, choices, scorer, 1, score_cutoff)
+        .into_iter()
+        .next()
+}
+
+/// A query preprocessed once for repeated [`jaro`] comparisons, caching the
+/// query's char vector so it isn't rebuilt on every candidate.
+pub struct JaroQuery {
+    chars: Vec<char>,
+}
+
+impl JaroQuery {
+    pub fn new(query: &str) -> Self {
+        JaroQuery {
+            chars: query.chars().collect(),
+        }
+    }
+
+    pub fn score(&self, candidate: &str) -> f64 {
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        generic_jaro(&self.chars, &candidate_chars)
+    }
+}
+
+/// A query preprocessed once for repeated [`sorensen_dice`] comparisons,
+/// caching the query's bigram multiset so it isn't rebuilt on every
+/// candidate.
+pub struct SorensenDiceQuery {
+    chars: Vec<char>,
+    bigrams: Vec<(char, char)>,
+}
+
+impl SorensenDiceQuery {
+    pub fn new(query: &str) -> Self {
+        SorensenDiceQuery {
+            chars: query.chars().collect(),
+            bigrams: bigrams(query),
+        }
+    }
+
+    pub fn score(&self, candidate: &str) -> f64 {
+        let candidate_bigrams = bigrams(candidate);
+
+        if self.bigrams.is_empty() && candidate_bigrams.is_empty() {
+            return if self.chars.iter().copied().eq(candidate.chars()) {
+                1.0
+            } else {
+                0.0
+            };
+        }
+
+        let mut candidate_counts: HashMap<(char, char), i32> = HashMap::new();
+        for bigram in &candidate_bigrams {
+            *candidate_counts.entry(*bigram).or_insert(0) += 1;
+        }
+
+        let mut intersection = 0usize;
+        for bigram in &self.bigrams {
+            if let Some(count) = candidate_counts.get_mut(bigram) {
+                if *count > 0 {
+                    *count -= 1;
+                    intersection += 1;
+                }
+            }
+        }
+
+        (2 * intersection) as f64 / (self.bigrams.len() + candidate_bigrams.len()) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_sorts_best_first_and_respects_limit() {
+        let choices = ["nacht", "night", "nacht rises"];
+        let matches = extract("night", &choices, Scorer::SorensenDice, 2, None);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].choice, "night");
+    }
+
+    #[test]
+    fn extract_respects_score_cutoff() {
+        let choices = ["night", "totally different"];
+        let matches = extract("night", &choices, Scorer::Jaro, 10, Some(0.9));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].choice, "night");
+    }
+
+    #[test]
+    fn extract_one_returns_none_when_nothing_clears_cutoff() {
+        let choices = ["totally different"];
+        assert!(extract_one("night", &choices, Scorer::Jaro, Some(0.99)).is_none());
+    }
+
+    #[test]
+    fn extract_jaro_scores_match_jaro_query() {
+        let choices = ["nacht", "totally different"];
+        let matches = extract("night", &choices, Scorer::Jaro, 10, None);
+        let query = JaroQuery::new("night");
+        for m in &matches {
+            assert_eq!(m.score, query.score(m.choice));
+        }
+    }
+
+    #[test]
+    fn extract_sorensen_dice_scores_match_sorensen_dice_query() {
+        let choices = ["nacht", "totally different"];
+        let matches = extract("night", &choices, Scorer::SorensenDice, 10, None);
+        let query = SorensenDiceQuery::new("night");
+        for m in &matches {
+            assert_eq!(m.score, query.score(m.choice));
+        }
+    }
+
+    #[test]
+    fn extract_uncached_scorers_still_work() {
+        let choices = ["night", "totally different"];
+        let jw = extract("night", &choices, Scorer::JaroWinkler, 1, None);
+        assert_eq!(jw[0].score, jaro_winkler("night", "night"));
+        let nl = extract("night", &choices, Scorer::NormalizedLevenshtein, 1, None);
+        assert_eq!(nl[0].score, normalized_levenshtein("night", "night"));
+    }
+
+    #[test]
+    fn jaro_query_matches_plain_jaro() {
+        let query = JaroQuery::new("night");
+        assert_eq!(query.score("nacht"), jaro("night", "nacht"));
+    }
+
+    #[test]
+    fn sorensen_dice_query_matches_plain_sorensen_dice() {
+        let query = SorensenDiceQuery::new("night");
+        assert_eq!(query.score("nacht"), sorensen_dice("night", "nacht"));
+    }
+}